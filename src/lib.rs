@@ -0,0 +1,3 @@
+//! A Rust implementation of EditorConfig.
+
+pub mod property;
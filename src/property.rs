@@ -2,6 +2,13 @@
 //!
 //! Includes the [crate::property::Property] trait
 //! as well as instances for common properties.
+//!
+//! With the `serde` cargo feature enabled,
+//! the enum-based properties (e.g. [`IndentStyle`], [`EndOfLine`], [`Charset`])
+//! implement `serde::Serialize`/`serde::Deserialize` using the exact `.editorconfig` spelling
+//! (`"tab"`, `"crlf"`, `"utf-8-bom"`, ...) rather than the Rust variant identifiers.
+//! Basic properties expose inherent `serialize`/`deserialize` functions
+//! for use with `#[serde(with = "...")]` on a field of the property's [`Value`](Property::Value) type.
 
 /// A trait for types that represent properties.
 ///
@@ -16,18 +23,137 @@ pub trait Property {
 	fn key() -> &'static str;
 	/// Parses a string value into the output type.
 	fn parse_value(raw: &str) -> Option<Self::Value>;
+	/// Serializes a value back into its canonical `.editorconfig` string form.
+	///
+	/// This is the inverse of [`parse_value`](Property::parse_value):
+	/// for any `raw` accepted by `parse_value`,
+	/// `to_raw` yields a string that `parse_value` would accept back into an equal value,
+	/// though it need not be the same `raw` string that was originally given.
+	fn to_raw(value: &Self::Value) -> String;
+	/// Parses a string value, returning a [`ValueError`] with context on failure.
+	///
+	/// The default implementation just wraps [`parse_value`](Property::parse_value)
+	/// with no accepted-value list or suggestion; [`property_enum!`]-generated properties
+	/// override this to report the accepted strings and, if close enough, a "did you mean" suggestion.
+	fn validate(raw: &str) -> Result<Self::Value, ValueError> {
+		Self::parse_value(raw).ok_or_else(|| ValueError {
+			key: Self::key(),
+			raw: raw.to_string(),
+			accepted: None,
+			suggestion: None,
+		})
+	}
+}
+
+/// The error returned by [`Property::validate`] when a raw string fails to parse.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ValueError {
+	/// The key of the property that failed to validate, per [`Property::key`].
+	pub key: &'static str,
+	/// The raw string that failed to parse.
+	pub raw: String,
+	/// The accepted string values for this property, if it is a fixed enum.
+	pub accepted: Option<&'static [&'static str]>,
+	/// The accepted value closest to `raw` by Levenshtein distance, if within 2 edits.
+	pub suggestion: Option<&'static str>,
+}
+
+impl std::fmt::Display for ValueError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "unknown value {:?} for {}", self.raw, self.key)?;
+		if let Some(suggestion) = self.suggestion {
+			write!(f, "; did you mean {:?}?", suggestion)?;
+		}
+		Ok(())
+	}
+}
+
+impl std::error::Error for ValueError {}
+
+/// Computes the Levenshtein edit distance between two byte strings.
+///
+/// Runs in O(min(m,n)) space using a classic two-row DP.
+fn levenshtein(a: &str, b: &str) -> usize {
+	let (a, b) = (a.as_bytes(), b.as_bytes());
+	let (short, long) = if a.len() <= b.len() {(a, b)} else {(b, a)};
+	let mut prev: Vec<usize> = (0..=short.len()).collect();
+	let mut curr: Vec<usize> = vec![0; short.len() + 1];
+	for (i, &lb) in long.iter().enumerate() {
+		curr[0] = i + 1;
+		for (j, &sb) in short.iter().enumerate() {
+			let cost = if lb == sb {0} else {1};
+			curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+		}
+		std::mem::swap(&mut prev, &mut curr);
+	}
+	prev[short.len()]
+}
+
+/// Introspection data for a standard EditorConfig property.
+///
+/// Gathered into [`STANDARD_PROPERTIES`] so that tooling (CLIs, LSPs, linters)
+/// can list every known key and its accepted values
+/// without hardcoding the property list themselves.
+///
+/// Not generic over a [`Property`] impl since [`Property`] is not object-safe:
+/// its associated [`Value`](Property::Value) type varies per property.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PropertyInfo {
+	/// The string key, matching [`Property::key`].
+	pub key: &'static str,
+	/// The accepted string values, if the property is a fixed enum.
+	///
+	/// `None` if the property instead accepts a range of values,
+	/// such as a number or a boolean.
+	pub possible_values: Option<&'static [&'static str]>,
+	/// A link to the property's documentation on the EditorConfig wiki.
+	pub doc_url: &'static str,
+}
+
+/// Looks up the [`PropertyInfo`] for a standard property by its [`Property::key`].
+pub fn info_for_key(key: &str) -> Option<&'static PropertyInfo> {
+	STANDARD_PROPERTIES.iter().find(|info| info.key == key)
 }
 
 macro_rules! property_basic_custom {
-	($prop_id:ident, $name:literal, $parse_as:ty, $parse_arg:ident, $parse_block:block) => {
+	($prop_id:ident, $name:literal, $parse_as:ty, $parse_arg:ident, $parse_block:block, $to_raw_arg:ident, $to_raw_block:block) => {
 		#[doc = concat!("The [`",$name,"`](https://github.com/editorconfig/editorconfig/wiki/EditorConfig-Properties#",$name,") property.")]
 		pub struct $prop_id;
+		impl $prop_id {
+			/// Introspection data for this property. See [`PropertyInfo`].
+			pub const INFO: PropertyInfo = PropertyInfo {
+				key: $name,
+				possible_values: None,
+				doc_url: concat!("https://github.com/editorconfig/editorconfig/wiki/EditorConfig-Properties#",$name),
+			};
+		}
 		impl Property for $prop_id {
 			type Value = $parse_as;
 			fn key() -> &'static str {$name}
 			fn parse_value($parse_arg: &str) -> Option<Self::Value> {
 				$parse_block
 			}
+			fn to_raw($to_raw_arg: &Self::Value) -> String {
+				$to_raw_block
+			}
+		}
+		#[cfg(feature = "serde")]
+		impl $prop_id {
+			/// Serializes a value via [`Property::to_raw`].
+			///
+			/// For use with `#[serde(with = "...")]` on a field of type [`Property::Value`](Property::Value).
+			pub fn serialize<S: serde::Serializer>(value: &<Self as Property>::Value, serializer: S) -> Result<S::Ok, S::Error> {
+				serializer.serialize_str(&Self::to_raw(value))
+			}
+			/// Deserializes a value via [`Property::parse_value`].
+			///
+			/// For use with `#[serde(with = "...")]` on a field of type [`Property::Value`](Property::Value).
+			pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<<Self as Property>::Value, D::Error> {
+				let raw = <std::borrow::Cow<str> as serde::Deserialize>::deserialize(deserializer)?;
+				Self::parse_value(&raw).ok_or_else(|| serde::de::Error::custom(
+					format!("unknown value {:?} for property {:?}", raw, Self::key())
+				))
+			}
 		}
 	}
 }
@@ -36,6 +162,8 @@ macro_rules! property_basic {
 	($prop_id:ident, $name:literal, $parse_as:ty) => {
 		property_basic_custom!{$prop_id, $name, $parse_as, raw, {
 			raw.parse::<$parse_as>().ok()
+		}, value, {
+			value.to_string()
 		}
 	}}
 }
@@ -48,6 +176,11 @@ macro_rules! property_basic_option {
 			} else {
 				raw.parse::<$parse_as>().ok().map(Some)
 			}
+		}, value, {
+			match value {
+				Some(value) => value.to_string(),
+				None => $disable.to_string()
+			}
 		}
 	}}
 }
@@ -58,6 +191,14 @@ macro_rules! property_enum {
 		#[repr(u8)]
 		#[doc = concat!("The [`",$name,"`](https://github.com/editorconfig/editorconfig/wiki/EditorConfig-Properties#",$name,") property.")]
 		pub enum $prop_id {$($variant),+}
+		impl $prop_id {
+			/// Introspection data for this property. See [`PropertyInfo`].
+			pub const INFO: PropertyInfo = PropertyInfo {
+				key: $name,
+				possible_values: Some(&[$($string),+]),
+				doc_url: concat!("https://github.com/editorconfig/editorconfig/wiki/EditorConfig-Properties#",$name),
+			};
+		}
 		impl Property for $prop_id {
 			type Value = $prop_id;
 			fn key() -> &'static str {$name}
@@ -67,6 +208,42 @@ macro_rules! property_enum {
 					_ => None
 				}
 			}
+			fn to_raw(value: &Self::Value) -> String {
+				match value {
+					$($prop_id::$variant => $string),+
+				}.to_string()
+			}
+			fn validate(raw: &str) -> Result<Self::Value, ValueError> {
+				Self::parse_value(raw).ok_or_else(|| {
+					let accepted = Self::INFO.possible_values.unwrap();
+					let suggestion = accepted.iter()
+						.map(|&candidate| (candidate, levenshtein(raw, candidate)))
+						.filter(|&(_, dist)| dist <= 2)
+						.min_by_key(|&(_, dist)| dist)
+						.map(|(candidate, _)| candidate);
+					ValueError {
+						key: Self::key(),
+						raw: raw.to_string(),
+						accepted: Some(accepted),
+						suggestion,
+					}
+				})
+			}
+		}
+		#[cfg(feature = "serde")]
+		impl serde::Serialize for $prop_id {
+			fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				serializer.serialize_str(&Self::to_raw(self))
+			}
+		}
+		#[cfg(feature = "serde")]
+		impl<'de> serde::Deserialize<'de> for $prop_id {
+			fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				let raw = <std::borrow::Cow<str> as serde::Deserialize>::deserialize(deserializer)?;
+				Self::parse_value(&raw).ok_or_else(|| serde::de::Error::custom(
+					format!("unknown value {:?} for property {:?}", raw, Self::key())
+				))
+			}
 		}
 	}
 }
@@ -106,3 +283,75 @@ property_enum!{
 property_basic!{TrimTrailingWs, "trim_trailing_whitespace", bool}
 property_basic!{FinalNewline, "insert_final_newline", bool}
 property_basic_option!{MaxLineLen, "max_line_length", usize, "off"}
+
+/// All standard EditorConfig properties known to this crate,
+/// for tooling that needs to list or validate keys without hardcoding them.
+pub const STANDARD_PROPERTIES: &[PropertyInfo] = &[
+	IndentStyle::INFO,
+	IndentSize::INFO,
+	TabWidth::INFO,
+	EndOfLine::INFO,
+	Charset::INFO,
+	TrimTrailingWs::INFO,
+	FinalNewline::INFO,
+	MaxLineLen::INFO,
+];
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn to_raw_emits_canonical_strings() {
+		assert_eq!(IndentStyle::to_raw(&IndentStyle::Tabs), "tab");
+		assert_eq!(IndentSize::to_raw(&None), "tab");
+		assert_eq!(MaxLineLen::to_raw(&None), "off");
+		assert_eq!(IndentSize::to_raw(&Some(4)), "4");
+	}
+
+	#[test]
+	fn standard_properties_cover_possible_values() {
+		assert_eq!(IndentStyle::INFO.possible_values, Some(&["tab", "space"][..]));
+		assert_eq!(TabWidth::INFO.possible_values, None);
+		assert_eq!(info_for_key("indent_style").unwrap().key, "indent_style");
+		assert!(info_for_key("no_such_property").is_none());
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn serde_uses_editorconfig_spelling() {
+		assert_eq!(serde_json::to_string(&IndentStyle::Tabs).unwrap(), "\"tab\"");
+		let parsed: IndentStyle = serde_json::from_str("\"space\"").unwrap();
+		assert_eq!(parsed, IndentStyle::Spaces);
+		assert!(serde_json::from_str::<IndentStyle>("\"tabs\"").is_err());
+	}
+
+	#[test]
+	fn levenshtein_known_pairs() {
+		assert_eq!(levenshtein("tab", "tab"), 0);
+		assert_eq!(levenshtein("tabs", "tab"), 1);
+		assert_eq!(levenshtein("kitten", "sitting"), 3);
+		assert_eq!(levenshtein("", "abc"), 3);
+	}
+
+	#[test]
+	fn validate_suggests_closest_match() {
+		let err = IndentStyle::validate("tabs").unwrap_err();
+		assert_eq!(err.accepted, Some(&["tab", "space"][..]));
+		assert_eq!(err.suggestion, Some("tab"));
+		assert_eq!(err.to_string(), "unknown value \"tabs\" for indent_style; did you mean \"tab\"?");
+	}
+
+	#[test]
+	fn validate_ties_prefer_declaration_order() {
+		// "lr" is distance 1 from both "lf" and "cr"; Lf is declared first.
+		let err = EndOfLine::validate("lr").unwrap_err();
+		assert_eq!(err.suggestion, Some("lf"));
+	}
+
+	#[test]
+	fn validate_reports_no_suggestion_when_too_far() {
+		let err = IndentStyle::validate("xyzzy").unwrap_err();
+		assert_eq!(err.suggestion, None);
+	}
+}